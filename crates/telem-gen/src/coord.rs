@@ -118,6 +118,103 @@ impl From<Heading> for f32 {
     }
 }
 
+//  _____ ____ _____ _____
+// | ____/ ___|  ___|  ___|
+// |  _|| |   | |_  | |_
+// | |__| |___|  _| |  _|
+// |_____\____|_|   |_|
+
+/// WGS84 semi-major axis, in meters.
+const WGS84_A: f64 = 6378137.0;
+/// WGS84 flattening.
+const WGS84_F: f64 = 1.0 / 298.257223563;
+
+/// A point in Earth-Centered, Earth-Fixed Cartesian coordinates, in meters.
+#[derive(Debug, Copy, Clone)]
+pub struct Point3dEcef {
+    pub x: f64,
+    pub y: f64,
+    pub z: f64,
+}
+
+impl Point3dEcef {
+    fn sub(self, other: Point3dEcef) -> Point3dEcef {
+        Point3dEcef {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+
+    fn dot(self, other: Point3dEcef) -> f64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    fn norm(self) -> f64 {
+        self.dot(self).sqrt()
+    }
+}
+
+/// Converts WGS84 geodetic coordinates (degrees, height in meters above the ellipsoid) to ECEF.
+pub fn geodetic_to_ecef(lat_deg: f64, lon_deg: f64, alt_m: f64) -> Point3dEcef {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let lat = lat_deg.to_radians();
+    let lon = lon_deg.to_radians();
+    let n = WGS84_A / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+    Point3dEcef {
+        x: (n + alt_m) * lat.cos() * lon.cos(),
+        y: (n + alt_m) * lat.cos() * lon.sin(),
+        z: (n * (1.0 - e2) + alt_m) * lat.sin(),
+    }
+}
+
+/// Converts ECEF coordinates back to WGS84 geodetic (lat/lon in degrees, height in meters above
+/// the ellipsoid), by five rounds of fixed-point iteration on latitude and height.
+pub fn ecef_to_geodetic(p: Point3dEcef) -> (f64, f64, f64) {
+    let e2 = WGS84_F * (2.0 - WGS84_F);
+    let lon = p.y.atan2(p.x);
+    let p_xy = (p.x * p.x + p.y * p.y).sqrt();
+
+    let mut lat = p.z.atan2(p_xy * (1.0 - e2));
+    let mut alt = 0.0;
+    for _ in 0..5 {
+        let n = WGS84_A / (1.0 - e2 * lat.sin().powi(2)).sqrt();
+        alt = p_xy / lat.cos() - n;
+        lat = p.z.atan2(p_xy * (1.0 - e2 * n / (n + alt)));
+    }
+
+    (lat.to_degrees(), lon.to_degrees(), alt)
+}
+
+/// Elevation angle (degrees above the local horizon) of `target` as seen from `observer`.
+pub fn elevation_deg(observer: Point3dEcef, target: Point3dEcef) -> f64 {
+    let up = observer;
+    let d = target.sub(observer);
+    90.0 - (up.dot(d) / (up.norm() * d.norm())).acos().to_degrees()
+}
+
+/// Azimuth angle (degrees clockwise from true north, in `[0, 360)`) of `target` as seen from
+/// `observer`.
+pub fn azimuth_deg(observer: Point3dEcef, target: Point3dEcef) -> f64 {
+    let d = target.sub(observer);
+    let north = Point3dEcef {
+        x: -observer.z * observer.x,
+        y: -observer.z * observer.y,
+        z: observer.x * observer.x + observer.y * observer.y,
+    };
+    let east = Point3dEcef {
+        x: -observer.y,
+        y: observer.x,
+        z: 0.0,
+    };
+    let az = east.dot(d).atan2(north.dot(d)).to_degrees();
+    if az < 0.0 {
+        az + 360.0
+    } else {
+        az
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -182,4 +279,48 @@ mod test {
         assert!((37119.0..37269.0).contains(&width));
         assert!((45719.5..45721.5).contains(&height));
     }
+
+    #[test]
+    fn test_geodetic_to_ecef_equator_prime_meridian() {
+        lazy_init_tracing();
+        // On the equator at the prime meridian, ECEF x should equal the WGS84 semi-major axis.
+        let p = geodetic_to_ecef(0.0, 0.0, 0.0);
+        assert!((p.x - WGS84_A).abs() < 1e-6);
+        assert!(p.y.abs() < 1e-6);
+        assert!(p.z.abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_ecef_geodetic_round_trip() {
+        lazy_init_tracing();
+        let (lat, lon, alt) = (37.4, -122.1, 1250.0);
+        let p = geodetic_to_ecef(lat, lon, alt);
+        let (lat2, lon2, alt2) = ecef_to_geodetic(p);
+        assert!((lat - lat2).abs() < 1e-9);
+        assert!((lon - lon2).abs() < 1e-9);
+        assert!((alt - alt2).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_elevation_straight_up() {
+        lazy_init_tracing();
+        // On the equator, the ellipsoid normal coincides with the geocentric position vector,
+        // so "up" (as computed from observer position) points exactly at a target directly
+        // above. Off the equator the two directions diverge slightly (deviation of the
+        // vertical), which this geocentric approximation does not model.
+        let observer = geodetic_to_ecef(0.0, -122.0, 0.0);
+        let target = geodetic_to_ecef(0.0, -122.0, 500_000.0);
+        let elev = elevation_deg(observer, target);
+        assert!((elev - 90.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_azimuth_due_north() {
+        lazy_init_tracing();
+        // A target directly north of the observer, at the same altitude, should read ~0 degrees.
+        let observer = geodetic_to_ecef(10.0, 0.0, 1000.0);
+        let target = geodetic_to_ecef(10.1, 0.0, 1000.0);
+        let az = azimuth_deg(observer, target);
+        assert!(!(1.0..=359.0).contains(&az));
+    }
 }
@@ -10,6 +10,7 @@ use thiserror::Error;
 pub mod coord;
 pub mod model;
 pub mod protocol;
+pub mod time;
 
 /// Result type for this library
 pub type TGResult<T> = std::result::Result<T, Error>;
@@ -8,8 +8,9 @@ use std::time::Duration;
 use tracing::debug;
 
 use crate::{
-    coord::{BBoxWGS, Heading, Point2d},
+    coord::{ecef_to_geodetic, BBoxWGS, Heading, Point2d, Point3dEcef},
     protocol::TelemMsg,
+    Error, TGResult,
 };
 
 //  _____
@@ -66,6 +67,7 @@ pub struct RandomWalk {
     max_velocity_mps: f32,
     pub(crate) last_pos: Point2d,
     heading: Heading,
+    msg_seq: u64,
 }
 
 impl RandomWalk {
@@ -77,6 +79,7 @@ impl RandomWalk {
             max_velocity_mps,
             last_pos: start_pos,
             heading: Heading(random_deg),
+            msg_seq: 0,
         }
     }
 }
@@ -134,14 +137,283 @@ where
             self.heading.rot(180.0);
         }
         self.last_pos = Point2d(new_lat, new_lon);
+        let seq = self.msg_seq;
+        self.msg_seq += 1;
         M::from_coords(new_lat, new_lon, 0.0)
+            // `heading` is measured counter-clockwise from east (see the delta_x/delta_y math
+            // above); course is reported clockwise from true north like the other models.
+            .with_velocity(vel, (90.0 - self.heading.0).rem_euclid(360.0), 0.0)
+            .with_seq(seq)
+    }
+}
+
+//  ____             _       _____     _ _
+// |  _ \ ___  _   _| |_ ___|  ___|__ | | | _____      __
+// | |_) / _ \| | | | __/ _ \ |_ / _ \| | |/ _ \ \ /\ / /
+// |  _ < (_) | |_| | ||  __/  _| (_) | | | (_) \ V  V /
+// |_| \_\___/ \__,_|\__\___|_|  \___/|_|_|\___/ \_/\_/
+
+/// Mean radius of the Earth, in meters (used for great-circle calculations).
+const EARTH_RADIUS_M: f64 = 6371000.0;
+
+/// Great-circle distance between two points, via the haversine formula.
+fn haversine_distance_m(a: Point2d, b: Point2d) -> f64 {
+    let phi1 = a.0.to_radians();
+    let phi2 = b.0.to_radians();
+    let dphi = (b.0 - a.0).to_radians();
+    let dlambda = (b.1 - a.1).to_radians();
+    let sin_dphi2 = (dphi / 2.0).sin();
+    let sin_dlambda2 = (dlambda / 2.0).sin();
+    let h = sin_dphi2 * sin_dphi2 + phi1.cos() * phi2.cos() * sin_dlambda2 * sin_dlambda2;
+    2.0 * EARTH_RADIUS_M * h.sqrt().asin()
+}
+
+/// Initial bearing (radians, clockwise from true north) of the great-circle path from `a` to `b`.
+fn initial_bearing_rad(a: Point2d, b: Point2d) -> f64 {
+    let phi1 = a.0.to_radians();
+    let phi2 = b.0.to_radians();
+    let dlambda = (b.1 - a.1).to_radians();
+    let y = dlambda.sin() * phi2.cos();
+    let x = phi1.cos() * phi2.sin() - phi1.sin() * phi2.cos() * dlambda.cos();
+    y.atan2(x)
+}
+
+/// Destination point reached by travelling `angular_dist_rad` along `bearing_rad` from `a`.
+fn destination_point(a: Point2d, bearing_rad: f64, angular_dist_rad: f64) -> Point2d {
+    let phi1 = a.0.to_radians();
+    let lambda1 = a.1.to_radians();
+    let phi2 = (phi1.sin() * angular_dist_rad.cos()
+        + phi1.cos() * angular_dist_rad.sin() * bearing_rad.cos())
+    .asin();
+    let lambda2 = lambda1
+        + (bearing_rad.sin() * angular_dist_rad.sin() * phi1.cos())
+            .atan2(angular_dist_rad.cos() - phi1.sin() * phi2.sin());
+    // Wrap longitude into [-180, 180) so routes crossing the antimeridian stay valid.
+    let lon_deg = (lambda2.to_degrees() + 540.0).rem_euclid(360.0) - 180.0;
+    Point2d(phi2.to_degrees(), lon_deg)
+}
+
+/// Safety valve on the number of waypoints a single `next()` tick may hop through. Without this,
+/// a looped route whose waypoints collapse to zero distance (e.g. duplicate points) would spin
+/// forever: `remaining_m` never shrinks, so the advance-to-next-waypoint branch keeps firing.
+const MAX_SEGMENT_HOPS_PER_TICK: u32 = 10_000;
+
+/// Follows a sequence of waypoints at a fixed ground speed, interpolating along great-circle
+/// segments. Unlike [`RandomWalk`], this works correctly in any hemisphere.
+pub struct RouteFollow {
+    waypoints: Vec<Point2d>,
+    speed_mps: f32,
+    seg_idx: usize,
+    pub(crate) last_pos: Point2d,
+    loop_route: bool,
+    finished: bool,
+    msg_seq: u64,
+}
+
+impl RouteFollow {
+    pub fn new(waypoints: Vec<Point2d>, speed_mps: f32, loop_route: bool) -> TGResult<Self> {
+        if waypoints.len() < 2 {
+            return Err(Error::InvalidCoord(
+                "RouteFollow requires at least 2 waypoints".to_string(),
+            ));
+        }
+        Ok(Self {
+            last_pos: waypoints[0],
+            waypoints,
+            speed_mps,
+            seg_idx: 0,
+            loop_route,
+            finished: false,
+            msg_seq: 0,
+        })
+    }
+}
+
+impl<M> TelemStream<M> for RouteFollow
+where
+    M: TelemMsg,
+{
+    fn next(&mut self, delta_t: TimeDelta) -> M {
+        let mut remaining_m = (delta_t.seconds() * self.speed_mps) as f64;
+        let mut course_deg = 0.0;
+        let mut hops = 0u32;
+        while remaining_m > 0.0 && !self.finished {
+            hops += 1;
+            if hops > MAX_SEGMENT_HOPS_PER_TICK {
+                debug!("route follow: exceeded {MAX_SEGMENT_HOPS_PER_TICK} segment hops in one tick (degenerate waypoints?), stopping early");
+                break;
+            }
+            let target = self.waypoints[self.seg_idx];
+            let seg_dist_m = haversine_distance_m(self.last_pos, target);
+            let bearing = initial_bearing_rad(self.last_pos, target);
+            course_deg = bearing.to_degrees();
+            if seg_dist_m <= remaining_m {
+                self.last_pos = target;
+                remaining_m -= seg_dist_m;
+                self.seg_idx += 1;
+                if self.seg_idx >= self.waypoints.len() {
+                    if self.loop_route {
+                        self.seg_idx = 0;
+                    } else {
+                        self.finished = true;
+                    }
+                }
+            } else {
+                let angular_dist = remaining_m / EARTH_RADIUS_M;
+                self.last_pos = destination_point(self.last_pos, bearing, angular_dist);
+                remaining_m = 0.0;
+            }
+        }
+        debug!("route follow pos: {:?}", self.last_pos);
+        let Point2d(lat, lon) = self.last_pos;
+        // A platform parked at the final waypoint (non-looping route) is stopped, not cruising.
+        let ground_speed_mps = if self.finished { 0.0 } else { self.speed_mps };
+        let seq = self.msg_seq;
+        self.msg_seq += 1;
+        M::from_coords(lat, lon, 0.0)
+            .with_velocity(ground_speed_mps, course_deg as f32, 0.0)
+            .with_seq(seq)
+    }
+}
+
+//  _  __           _           ___       _     _ _
+// | |/ /___ _ __  | | ___ _ __/ _ \ _ __| |__ (_) |_
+// | ' // _ \ '_ \ | |/ _ \ '__| | | | '__| '_ \| | __|
+// | . \  __/ |_) || |  __/ |  | |_| | |  | |_) | | |_
+// |_|\_\___| .__/ |_|\___|_|   \___/|_|  |_.__/|_|\__|
+//          |_|
+
+/// Standard gravitational parameter of Earth, in m^3/s^2.
+const MU_EARTH: f64 = 3.986004418e14;
+/// Earth's rotation rate, in rad/s.
+const OMEGA_EARTH: f64 = 7.2921151467e-5;
+
+/// Propagates a satellite from classical (broadcast-style) orbital elements and emits its
+/// sub-satellite point each tick. Angles are in degrees; `sqrt_a` is the square root of the
+/// semi-major axis in meters (as used in GPS/GNSS broadcast ephemerides).
+pub struct KeplerOrbit {
+    semi_major_axis_m: f64,
+    eccentricity: f64,
+    inclination_deg: f64,
+    raan_deg: f64,
+    arg_perigee_deg: f64,
+    mean_anomaly_epoch_deg: f64,
+    elapsed_s: f64,
+    /// Sub-satellite point and altitude from the previous tick, used to derive ground speed and
+    /// course by finite difference. `None` until the first `next()` call.
+    last_state: Option<(Point2d, f32)>,
+    msg_seq: u64,
+}
+
+impl KeplerOrbit {
+    pub fn new(
+        sqrt_a: f64,
+        eccentricity: f64,
+        inclination_deg: f64,
+        raan_deg: f64,
+        arg_perigee_deg: f64,
+        mean_anomaly_epoch_deg: f64,
+    ) -> Self {
+        Self {
+            semi_major_axis_m: sqrt_a * sqrt_a,
+            eccentricity,
+            inclination_deg,
+            raan_deg,
+            arg_perigee_deg,
+            mean_anomaly_epoch_deg,
+            elapsed_s: 0.0,
+            last_state: None,
+            msg_seq: 0,
+        }
+    }
+}
+
+impl<M> TelemStream<M> for KeplerOrbit
+where
+    M: TelemMsg,
+{
+    fn next(&mut self, delta_t: TimeDelta) -> M {
+        self.elapsed_s += delta_t.seconds() as f64;
+
+        let a = self.semi_major_axis_m;
+        let e = self.eccentricity;
+        let n = (MU_EARTH / a.powi(3)).sqrt();
+        let mean_anomaly = self.mean_anomaly_epoch_deg.to_radians() + n * self.elapsed_s;
+
+        // Solve Kepler's equation for eccentric anomaly via Newton iteration.
+        let mut ecc_anomaly = mean_anomaly;
+        for _ in 0..5 {
+            ecc_anomaly -= (ecc_anomaly - e * ecc_anomaly.sin() - mean_anomaly)
+                / (1.0 - e * ecc_anomaly.cos());
+        }
+
+        let true_anomaly = ((1.0 - e * e).sqrt() * ecc_anomaly.sin()).atan2(ecc_anomaly.cos() - e);
+        let r = a * (1.0 - e * ecc_anomaly.cos());
+
+        // Position in the orbital plane, then rotate by argument of perigee, inclination, and
+        // RAAN into an Earth-centered inertial frame.
+        let x_orb = r * true_anomaly.cos();
+        let y_orb = r * true_anomaly.sin();
+
+        let (sin_w, cos_w) = self.arg_perigee_deg.to_radians().sin_cos();
+        let (sin_i, cos_i) = self.inclination_deg.to_radians().sin_cos();
+        let (sin_o, cos_o) = self.raan_deg.to_radians().sin_cos();
+
+        let x_pf = cos_w * x_orb - sin_w * y_orb;
+        let y_pf = sin_w * x_orb + cos_w * y_orb;
+
+        let x_eci = cos_o * x_pf - sin_o * cos_i * y_pf;
+        let y_eci = sin_o * x_pf + cos_o * cos_i * y_pf;
+        let z_eci = sin_i * y_pf;
+
+        // Rotate into Earth-fixed ECEF to account for Earth's rotation since epoch.
+        let theta = -OMEGA_EARTH * self.elapsed_s;
+        let (sin_t, cos_t) = theta.sin_cos();
+        let ecef = Point3dEcef {
+            x: cos_t * x_eci - sin_t * y_eci,
+            y: sin_t * x_eci + cos_t * y_eci,
+            z: z_eci,
+        };
+
+        let (lat, lon, alt) = ecef_to_geodetic(ecef);
+        debug!(
+            "kepler orbit sub-satellite point: {:.4}, {:.4}, alt {:.1}",
+            lat, lon, alt
+        );
+
+        let current_pos = Point2d(lat, lon);
+        let alt_m = alt as f32;
+        // Ground speed/course/vertical rate derived by finite difference against the previous
+        // tick; the first tick has no prior state to difference against, so reports zero.
+        let (ground_speed_mps, course_deg, vertical_rate_mps) = match self.last_state {
+            Some((prev_pos, prev_alt_m)) => {
+                let dist_m = haversine_distance_m(prev_pos, current_pos);
+                let bearing_deg = initial_bearing_rad(prev_pos, current_pos).to_degrees();
+                (
+                    (dist_m / delta_t.seconds() as f64) as f32,
+                    bearing_deg as f32,
+                    (alt_m - prev_alt_m) / delta_t.seconds(),
+                )
+            }
+            None => (0.0, 0.0, 0.0),
+        };
+        self.last_state = Some((current_pos, alt_m));
+
+        let seq = self.msg_seq;
+        self.msg_seq += 1;
+        M::from_coords(lat, lon, alt_m)
+            .with_velocity(ground_speed_mps, course_deg, vertical_rate_mps)
+            .with_seq(seq)
     }
 }
 
 #[cfg(test)]
 mod test {
     use super::*;
-    use crate::{lazy_init_tracing, protocol::cot::CotXml};
+    use crate::{
+        lazy_init_tracing,
+        protocol::{adsb::AdsbPosition, cot::CotXml},
+    };
     use tracing::trace;
 
     #[test]
@@ -161,4 +433,122 @@ mod test {
             assert!((-110.0..=-109.5).contains(&lon));
         }
     }
+
+    #[test]
+    fn test_route_follow_reaches_waypoints() {
+        lazy_init_tracing();
+        // A square route crossing the equator and the prime meridian, where RandomWalk's
+        // clamping hack would misbehave.
+        let waypoints = vec![
+            Point2d(1.0, -1.0),
+            Point2d(1.0, 1.0),
+            Point2d(-1.0, 1.0),
+            Point2d(-1.0, -1.0),
+        ];
+        let mut route = RouteFollow::new(waypoints.clone(), 5000.0, false).unwrap();
+        for _ in 0..1000 {
+            let _msg: CotXml = route.next(Duration::new(60, 0).into());
+            if route.finished {
+                break;
+            }
+        }
+        assert!(route.finished);
+        let Point2d(lat, lon) = route.last_pos;
+        assert!((lat - waypoints[3].0).abs() < 1e-6);
+        assert!((lon - waypoints[3].1).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_route_follow_loops() {
+        lazy_init_tracing();
+        let waypoints = vec![Point2d(0.0, 0.0), Point2d(0.0, 1.0)];
+        let mut route = RouteFollow::new(waypoints, 50000.0, true).unwrap();
+        for _ in 0..20 {
+            let _msg: CotXml = route.next(Duration::new(60, 0).into());
+            assert!(!route.finished);
+        }
+    }
+
+    #[test]
+    fn test_route_follow_reports_zero_speed_once_finished() {
+        lazy_init_tracing();
+        let waypoints = vec![Point2d(1.0, -1.0), Point2d(1.0, 1.0)];
+        let mut route = RouteFollow::new(waypoints, 5000.0, false).unwrap();
+        for _ in 0..1000 {
+            if route.finished {
+                break;
+            }
+            let _msg: CotXml = route.next(Duration::new(60, 0).into());
+        }
+        assert!(route.finished);
+        let msg: CotXml = route.next(Duration::new(60, 0).into());
+        assert_eq!(msg.detail.track.speed, 0.0);
+    }
+
+    #[test]
+    fn test_route_follow_interleaved_streams_alternate_adsb_parity_independently() {
+        lazy_init_tracing();
+        // Two independently-driven aircraft, round-robin ticked on one thread: each stream's own
+        // sequence counter must alternate its own ADS-B CPR frame parity without the other
+        // stream's calls perturbing it.
+        let mut aircraft_a =
+            RouteFollow::new(vec![Point2d(1.0, -1.0), Point2d(1.0, 1.0)], 5000.0, true).unwrap();
+        let mut aircraft_b =
+            RouteFollow::new(vec![Point2d(2.0, -2.0), Point2d(2.0, 2.0)], 5000.0, true).unwrap();
+
+        let mut a_parities = Vec::new();
+        let mut b_parities = Vec::new();
+        for _ in 0..4 {
+            let msg: AdsbPosition = aircraft_a.next(Duration::new(60, 0).into());
+            a_parities.push(msg.parity);
+            let msg: AdsbPosition = aircraft_b.next(Duration::new(60, 0).into());
+            b_parities.push(msg.parity);
+        }
+        for pair in a_parities.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        for pair in b_parities.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_route_follow_degenerate_waypoints_terminates() {
+        lazy_init_tracing();
+        // Coincident waypoints on a looped route never advance `remaining_m`; without a hop
+        // cap, `next()` would spin forever instead of returning.
+        let waypoints = vec![Point2d(1.0, 1.0), Point2d(1.0, 1.0)];
+        let mut route = RouteFollow::new(waypoints, 5000.0, true).unwrap();
+        let _msg: CotXml = route.next(Duration::new(60, 0).into());
+        assert!(!route.finished);
+    }
+
+    #[test]
+    fn test_kepler_orbit_altitude_near_circular_radius() {
+        lazy_init_tracing();
+        // Roughly ISS-like circular LEO: a ~ 6798 km, e ~ 0.
+        let sqrt_a = 6_798_000_f64.sqrt();
+        let mut orbit = KeplerOrbit::new(sqrt_a, 0.0003, 51.6, 0.0, 0.0, 0.0);
+        for _ in 0..30 {
+            let msg: CotXml = orbit.next(Duration::new(60, 0).into());
+            trace!("msg: {:?}", msg);
+            assert!((-90.0..=90.0).contains(&msg.point.lat));
+            assert!((-180.0..=180.0).contains(&msg.point.lon));
+            assert!((380_000.0..=460_000.0).contains(&msg.point.hae));
+        }
+    }
+
+    #[test]
+    fn test_kepler_orbit_reports_ground_speed_from_second_tick() {
+        lazy_init_tracing();
+        let sqrt_a = 6_798_000_f64.sqrt();
+        let mut orbit = KeplerOrbit::new(sqrt_a, 0.0003, 51.6, 0.0, 0.0, 0.0);
+
+        let first: CotXml = orbit.next(Duration::new(60, 0).into());
+        assert_eq!(first.detail.track.speed, 0.0);
+
+        let second: CotXml = orbit.next(Duration::new(60, 0).into());
+        // ISS-like LEO ground speed is on the order of 7 km/s.
+        assert!((4000.0..=9000.0).contains(&second.detail.track.speed));
+    }
 }
@@ -0,0 +1,243 @@
+/// Mode S Extended Squitter (ADS-B) airborne position messages (DF17), per RTCA DO-260B.
+///
+/// Implements Compact Position Reporting (CPR) encoding so generated aircraft tracks can be
+/// replayed into ADS-B decoders that reconstruct global position from alternating even/odd
+/// frames.
+use super::TelemMsg;
+
+const CPR_NB: u32 = 17;
+const CPR_RESOLUTION: f64 = 131072.0; // 2^17
+
+/// Even/odd frame parity, per CPR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Parity {
+    Even,
+    Odd,
+}
+
+impl Parity {
+    fn bit(self) -> i64 {
+        match self {
+            Parity::Even => 0,
+            Parity::Odd => 1,
+        }
+    }
+
+    fn i(self) -> f64 {
+        match self {
+            Parity::Even => 0.0,
+            Parity::Odd => 1.0,
+        }
+    }
+
+    /// Parity of the `seq`-th message in a stream (0-indexed), so successive messages alternate.
+    fn from_seq(seq: u64) -> Self {
+        if seq.is_multiple_of(2) {
+            Parity::Even
+        } else {
+            Parity::Odd
+        }
+    }
+}
+
+fn modulo(a: f64, b: f64) -> f64 {
+    a - b * (a / b).floor()
+}
+
+/// Number of longitude zones at a given latitude (NL function, per the CPR spec), clamped to 1
+/// near the poles where longitude zones are undefined.
+fn cpr_nl(lat: f64) -> i64 {
+    if lat.abs() >= 87.0 {
+        return 1;
+    }
+    let lat_rad = lat.to_radians();
+    let cos_term = (1.0 - (std::f64::consts::PI / 30.0).cos()) / (lat_rad.cos().powi(2));
+    let nl = 2.0 * std::f64::consts::PI / (1.0 - cos_term).acos();
+    nl.floor().max(1.0) as i64
+}
+
+/// Encodes a lat/lon pair into the 17-bit CPR lat/lon fields for the given frame parity.
+fn cpr_encode(lat: f64, lon: f64, parity: Parity) -> (u32, u32) {
+    let i = parity.i();
+    let dlat = 360.0 / (60.0 - i);
+    let yz = (CPR_RESOLUTION * (modulo(lat, dlat) / dlat) + 0.5).floor();
+    let rlat = dlat * (yz / CPR_RESOLUTION + (lat / dlat).floor());
+
+    let nl = (cpr_nl(rlat) - parity.bit()).max(1);
+    let dlon = 360.0 / nl as f64;
+    let xz = (CPR_RESOLUTION * (modulo(lon, dlon) / dlon) + 0.5).floor();
+
+    (
+        yz as u32 & ((1 << CPR_NB) - 1),
+        xz as u32 & ((1 << CPR_NB) - 1),
+    )
+}
+
+/// ADS-B CRC generator polynomial (0xFFF409, as a 24-bit value) applied MSB-first.
+const CRC_POLY: u32 = 0x00FF_F409;
+
+fn crc24(bytes: &[u8]) -> u32 {
+    // Compute over all but the trailing 3 CRC bytes, treating the message as a 112-bit
+    // polynomial and the generator as degree-24.
+    let mut reg: u32 = 0;
+    for &byte in bytes {
+        reg ^= (byte as u32) << 16;
+        for _ in 0..8 {
+            reg <<= 1;
+            if reg & (1 << 24) != 0 {
+                reg ^= CRC_POLY;
+            }
+        }
+    }
+    reg & 0x00FF_FFFF
+}
+
+/// ADS-B airborne position message (DF17, type codes 9-18). Frame parity alternates via
+/// [`TelemMsg::with_seq`], which a `TelemStream` impl calls with its own per-stream message
+/// counter (see [`crate::model`]) so each independently-driven aircraft track alternates
+/// correctly on its own, rather than sharing a single global sequence with every other track.
+#[derive(Debug, Clone)]
+pub struct AdsbPosition {
+    icao_address: u32,
+    lat: f64,
+    lon: f64,
+    altitude_ft: f32,
+    pub(crate) parity: Parity,
+}
+
+const TYPE_CODE_AIRBORNE_POSITION_BARO: u8 = 11;
+
+// No `with_velocity` override: DF17 airborne-position messages (type codes 9-18, what this
+// struct encodes) carry no speed/course field. Ground speed and course are reported by the
+// separate airborne-velocity message (type code 19), which isn't modeled here, so the trait's
+// no-op default is intentional rather than an oversight.
+impl TelemMsg for AdsbPosition {
+    fn from_coords(lat: f64, lon: f64, alt_hae: f32) -> Self {
+        Self {
+            icao_address: 0,
+            lat,
+            lon,
+            altitude_ft: alt_hae * 3.280_84, // meters to feet
+            parity: Parity::Even,
+        }
+    }
+
+    fn with_seq(mut self, seq: u64) -> Self {
+        self.parity = Parity::from_seq(seq);
+        self
+    }
+
+    fn with_agent_id(mut self, agent_id: &str) -> Self {
+        let hex: String = agent_id.chars().filter(|c| c.is_ascii_hexdigit()).collect();
+        self.icao_address = u32::from_str_radix(&hex, 16).unwrap_or(0) & 0x00FF_FFFF;
+        self
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let (lat_cpr, lon_cpr) = cpr_encode(self.lat, self.lon, self.parity);
+
+        // Altitude encoded in 25-ft increments with the Q-bit set (standard barometric encoding).
+        let alt_code = ((self.altitude_ft + 1000.0) / 25.0).round() as u32 & 0x7FF;
+        let alt_field = ((alt_code & 0x7F0) << 1) | 0x10 | (alt_code & 0x0F); // Q-bit inserted at bit 4
+
+        let mut bits: Vec<u8> = Vec::with_capacity(112);
+        let mut push = |value: u32, nbits: u32| {
+            for i in (0..nbits).rev() {
+                bits.push(((value >> i) & 1) as u8);
+            }
+        };
+
+        push(17, 5); // DF17
+        push(0, 3); // CA (capability), not modeled
+        push(self.icao_address, 24);
+        push(TYPE_CODE_AIRBORNE_POSITION_BARO as u32, 5);
+        push(0, 2); // surveillance status
+        push(0, 1); // single antenna flag
+        push(alt_field, 12);
+        push(0, 1); // UTC time synchronization flag
+        push(self.parity.bit() as u32, 1);
+        push(lat_cpr, CPR_NB);
+        push(lon_cpr, CPR_NB);
+
+        let mut out = vec![0u8; 11];
+        for (i, bit) in bits.iter().enumerate() {
+            if *bit == 1 {
+                out[i / 8] |= 1 << (7 - (i % 8));
+            }
+        }
+
+        let crc = crc24(&out);
+        out.push((crc >> 16) as u8);
+        out.push((crc >> 8) as u8);
+        out.push(crc as u8);
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_adsb_message_length_and_crc() {
+        let msg = AdsbPosition::from_coords(45.6, -122.7, 35000.0).with_agent_id("a1b2c3");
+        let bytes = msg.to_bytes();
+        assert_eq!(bytes.len(), 14);
+        assert_eq!(bytes[1], 0xa1);
+        assert_eq!(bytes[2], 0xb2);
+        assert_eq!(bytes[3], 0xc3);
+
+        // CRC24 over the first 11 bytes should reproduce the trailing 3.
+        let crc = crc24(&bytes[..11]);
+        assert_eq!(bytes[11], (crc >> 16) as u8);
+        assert_eq!(bytes[12], (crc >> 8) as u8);
+        assert_eq!(bytes[13], crc as u8);
+    }
+
+    #[test]
+    fn test_adsb_with_seq_alternates_parity() {
+        let parities: Vec<Parity> = (0..4)
+            .map(|seq| {
+                AdsbPosition::from_coords(10.0, 20.0, 1000.0)
+                    .with_seq(seq)
+                    .parity
+            })
+            .collect();
+        for pair in parities.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        assert_eq!(parities[0], Parity::Even);
+    }
+
+    #[test]
+    fn test_adsb_interleaved_streams_alternate_independently() {
+        // Each stream owns its own sequence counter, so round-robin calls across two
+        // independent aircraft must not perturb each other's parity alternation.
+        let mut a_parities = Vec::new();
+        let mut b_parities = Vec::new();
+        for seq in 0..4 {
+            a_parities.push(
+                AdsbPosition::from_coords(10.0, 20.0, 1000.0)
+                    .with_seq(seq)
+                    .parity,
+            );
+            b_parities.push(
+                AdsbPosition::from_coords(30.0, 40.0, 2000.0)
+                    .with_seq(seq)
+                    .parity,
+            );
+        }
+        for pair in a_parities.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+        for pair in b_parities.windows(2) {
+            assert_ne!(pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn test_cpr_nl_clamped_near_poles() {
+        assert_eq!(cpr_nl(89.9), 1);
+        assert_eq!(cpr_nl(-89.9), 1);
+    }
+}
@@ -0,0 +1,193 @@
+/// AIS (Automatic Identification System) AIVDM sentence encoding, per ITU-R M.1371.
+///
+/// Only AIS Class-A Position Report (message type 1) is implemented, which is sufficient to
+/// feed generated tracks into marine-traffic tooling that consumes NMEA-armored AIVDM sentences.
+use super::{nmea_checksum, TelemMsg};
+
+/// Nav status value meaning "not defined" (ITU-R M.1371 Table 45).
+const NAV_STATUS_UNDEFINED: u8 = 15;
+/// Rate-of-turn sentinel meaning "not available" (128, encoded as an 8-bit two's complement value).
+const ROT_NOT_AVAILABLE: u8 = 128;
+/// True heading sentinel meaning "not available".
+const HEADING_NOT_AVAILABLE: u16 = 511;
+
+/// AIS Class-A Position Report (message type 1).
+#[derive(Debug, Clone)]
+pub struct AisPosition {
+    mmsi: u32,
+    nav_status: u8,
+    rate_of_turn: u8,
+    sog_tenths_knot: u16,
+    position_accuracy: u8,
+    lon: f64,
+    lat: f64,
+    cog_tenths_deg: u16,
+    true_heading: u16,
+    utc_second: u8,
+}
+
+/// Appends `nbits` bits of `value` (MSB first) to `bits`.
+fn push_bits(bits: &mut Vec<u8>, value: i64, nbits: u32) {
+    for i in (0..nbits).rev() {
+        bits.push(((value >> i) & 1) as u8);
+    }
+}
+
+/// Splits a 6-bit-aligned bit buffer into NMEA/AIS armor characters: each 6-bit group is
+/// offset by 0x30, and if that exceeds 0x57, bumped by an additional 8.
+fn armor(bits: &[u8]) -> String {
+    bits.chunks(6)
+        .map(|chunk| {
+            let mut v: u8 = 0;
+            for bit in chunk {
+                v = (v << 1) | bit;
+            }
+            let c = v + 0x30;
+            let c = if c > 0x57 { c + 8 } else { c };
+            c as char
+        })
+        .collect()
+}
+
+impl TelemMsg for AisPosition {
+    fn from_coords(lat: f64, lon: f64, _alt_hae: f32) -> Self {
+        Self {
+            mmsi: 0,
+            nav_status: NAV_STATUS_UNDEFINED,
+            rate_of_turn: ROT_NOT_AVAILABLE,
+            sog_tenths_knot: 0,
+            position_accuracy: 0,
+            lon,
+            lat,
+            cog_tenths_deg: 0,
+            true_heading: HEADING_NOT_AVAILABLE,
+            utc_second: 60, // 60 = "not available" per spec
+        }
+    }
+
+    fn with_agent_id(mut self, agent_id: &str) -> Self {
+        let digits: String = agent_id.chars().filter(|c| c.is_ascii_digit()).collect();
+        self.mmsi = digits.parse::<u32>().unwrap_or(0) & 0x3fff_ffff;
+        self
+    }
+
+    fn with_velocity(
+        mut self,
+        ground_speed_mps: f32,
+        course_deg: f32,
+        _vertical_rate_mps: f32,
+    ) -> Self {
+        const MPS_TO_KNOTS: f32 = 1.943_844_5;
+        // 1023 = "speed not available", so clamp just below it.
+        self.sog_tenths_knot = ((ground_speed_mps * MPS_TO_KNOTS * 10.0).round() as u16).min(1022);
+        self.cog_tenths_deg = ((course_deg.rem_euclid(360.0) * 10.0).round() as u16).min(3599);
+        self
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut bits = Vec::with_capacity(168);
+        push_bits(&mut bits, 1, 6); // message type 1
+        push_bits(&mut bits, 0, 2); // repeat indicator
+        push_bits(&mut bits, self.mmsi as i64, 30);
+        push_bits(&mut bits, self.nav_status as i64, 4);
+        push_bits(&mut bits, self.rate_of_turn as i64, 8);
+        push_bits(&mut bits, self.sog_tenths_knot as i64, 10);
+        push_bits(&mut bits, self.position_accuracy as i64, 1);
+        push_bits(&mut bits, (self.lon * 600000.0).round() as i64, 28);
+        push_bits(&mut bits, (self.lat * 600000.0).round() as i64, 27);
+        push_bits(&mut bits, self.cog_tenths_deg as i64, 12);
+        push_bits(&mut bits, self.true_heading as i64, 9);
+        push_bits(&mut bits, self.utc_second as i64, 6);
+        bits.resize(168, 0); // remaining fields (maneuver, spare, RAIM, comm state) zero-padded
+
+        let payload = armor(&bits);
+        let body = format!("AIVDM,1,1,,A,{payload},0");
+        let checksum = nmea_checksum(&body);
+        format!("!{body}*{checksum:02X}\r\n").into_bytes()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Decodes a single armor character back to its 6-bit value.
+    fn unarmor_char(c: u8) -> u8 {
+        let v = c - 0x30;
+        if v > 0x28 {
+            v - 8
+        } else {
+            v
+        }
+    }
+
+    /// Decodes an armored payload back into its bit buffer.
+    fn unarmor(payload: &str) -> Vec<u8> {
+        let mut bits = Vec::with_capacity(payload.len() * 6);
+        for c in payload.bytes() {
+            let v = unarmor_char(c);
+            for i in (0..6).rev() {
+                bits.push((v >> i) & 1);
+            }
+        }
+        bits
+    }
+
+    fn bits_to_signed(bits: &[u8]) -> i64 {
+        let nbits = bits.len() as u32;
+        let mut v: i64 = 0;
+        for bit in bits {
+            v = (v << 1) | *bit as i64;
+        }
+        // sign-extend
+        let sign_bit = 1i64 << (nbits - 1);
+        if v & sign_bit != 0 {
+            v -= 1i64 << nbits;
+        }
+        v
+    }
+
+    #[test]
+    fn test_ais_position_round_trip() {
+        let lat = 45.6001;
+        let lon = -122.7002;
+        let msg = AisPosition::from_coords(lat, lon, 0.0).with_agent_id("ship-366123456");
+        assert_eq!(msg.mmsi, 366123456);
+
+        let bytes = msg.to_bytes();
+        let sentence = String::from_utf8(bytes).unwrap();
+        assert!(sentence.starts_with("!AIVDM,1,1,,A,"));
+
+        let body = sentence.trim_start_matches('!');
+        let (body, tail) = body.split_once('*').unwrap();
+        let checksum_hex = &tail[..2];
+        assert_eq!(
+            nmea_checksum(body),
+            u8::from_str_radix(checksum_hex, 16).unwrap()
+        );
+
+        let payload = body.split(',').nth(5).unwrap();
+        let bits = unarmor(payload);
+
+        let lon_bits = &bits[61..89];
+        let lat_bits = &bits[89..116];
+        let decoded_lon = bits_to_signed(lon_bits) as f64 / 600000.0;
+        let decoded_lat = bits_to_signed(lat_bits) as f64 / 600000.0;
+
+        assert!((decoded_lon - lon).abs() < 1.0 / 600000.0);
+        assert!((decoded_lat - lat).abs() < 1.0 / 600000.0);
+    }
+
+    #[test]
+    fn test_ais_with_velocity_converts_units_and_clamps() {
+        // 10 m/s -> 194.38 tenths-of-a-knot, rounds to 194.
+        let msg = AisPosition::from_coords(0.0, 0.0, 0.0).with_velocity(10.0, 45.0, 0.0);
+        assert_eq!(msg.sog_tenths_knot, 194);
+        assert_eq!(msg.cog_tenths_deg, 450);
+
+        // Absurd speed clamps just below the "not available" sentinel; course wraps into [0, 360).
+        let clamped = AisPosition::from_coords(0.0, 0.0, 0.0).with_velocity(1000.0, 720.0, 0.0);
+        assert_eq!(clamped.sog_tenths_knot, 1022);
+        assert_eq!(clamped.cog_tenths_deg, 0);
+    }
+}
@@ -19,6 +19,17 @@ impl TelemMsg for CotXml {
         self
     }
 
+    fn with_velocity(
+        mut self,
+        ground_speed_mps: f32,
+        course_deg: f32,
+        _vertical_rate_mps: f32,
+    ) -> Self {
+        self.detail.track.course = course_deg as f64;
+        self.detail.track.speed = ground_speed_mps as f64;
+        self
+    }
+
     fn to_bytes(&self) -> Vec<u8> {
         quick_xml::se::to_string(self).unwrap().into_bytes()
     }
@@ -38,4 +49,16 @@ mod test {
         assert_eq!(cot.uid, cot2.uid);
         assert_eq!(cot2.detail.contact.callsign, "whiskey_foxtrot");
     }
+
+    #[test]
+    fn test_cot_with_velocity_populates_track() {
+        let cot = CotXml::from_coords(45.6, -122.7, 101.0).with_velocity(12.5, 270.0, 0.0);
+        assert_eq!(cot.detail.track.speed, 12.5);
+        assert_eq!(cot.detail.track.course, 270.0);
+
+        let bytes = cot.to_bytes();
+        let cot2: CotXml = quick_xml::de::from_str(&String::from_utf8(bytes).unwrap()).unwrap();
+        assert_eq!(cot2.detail.track.speed, 12.5);
+        assert_eq!(cot2.detail.track.course, 270.0);
+    }
 }
@@ -1,9 +1,54 @@
+pub mod adsb;
+pub mod ais;
 pub mod cot;
+pub mod nmea;
 pub mod stanag_4586;
 
+use crate::time::UtcTime;
+
 /// Trait implemented by protocols for generating telemetry messages.
 pub trait TelemMsg {
     fn from_coords(lat: f64, lon: f64, alt_hae: f32) -> Self;
     fn with_agent_id(self, agent_id: &str) -> Self;
+
+    /// Attaches instantaneous kinematics to the message. Protocols with no native field for a
+    /// given quantity may ignore it; the default is a no-op for protocols that carry position
+    /// only.
+    fn with_velocity(self, ground_speed_mps: f32, course_deg: f32, vertical_rate_mps: f32) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = (ground_speed_mps, course_deg, vertical_rate_mps);
+        self
+    }
+
+    /// Attaches a UTC timestamp to the message. Protocols with no native time field (most
+    /// binary telemetry formats) may ignore it; the default is a no-op.
+    fn with_timestamp(self, ts: UtcTime) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = ts;
+        self
+    }
+
+    /// Attaches this message's position in its stream's sequence of `next()` calls, starting at
+    /// 0. Protocols whose on-wire encoding depends on message order (e.g. ADS-B's alternating
+    /// CPR frame parity) derive that state from this instead of hidden global counters; the
+    /// default is a no-op for protocols that don't need it.
+    fn with_seq(self, seq: u64) -> Self
+    where
+        Self: Sized,
+    {
+        let _ = seq;
+        self
+    }
+
     fn to_bytes(&self) -> Vec<u8>;
 }
+
+/// XOR checksum over the characters between `$`/`!` and `*` in an NMEA-style sentence, shared by
+/// the NMEA 0183 and AIS (AIVDM-over-NMEA) encoders.
+pub(crate) fn nmea_checksum(body: &str) -> u8 {
+    body.bytes().fold(0u8, |acc, b| acc ^ b)
+}
@@ -0,0 +1,151 @@
+/// NMEA 0183 GPS receiver sentences (`$GPRMC` and `$GPGGA`), for replaying generated tracks into
+/// GNSS-ingesting tools.
+use super::{nmea_checksum, TelemMsg};
+use crate::time::UtcTime;
+
+/// Fix quality placeholder: GPS fix (no DGPS/RTK modeling in the generator).
+const FIX_QUALITY_GPS: u8 = 1;
+/// Satellites-in-view placeholder.
+const SATELLITES_IN_VIEW: u8 = 8;
+/// HDOP placeholder.
+const HDOP: f32 = 0.9;
+
+#[derive(Debug, Clone)]
+pub struct NmeaPosition {
+    lat: f64,
+    lon: f64,
+    alt_hae: f32,
+    ground_speed_mps: f32,
+    course_deg: f32,
+    timestamp: UtcTime,
+}
+
+/// Formats a latitude as NMEA `ddmm.mmmm` plus its hemisphere letter.
+fn format_lat(lat: f64) -> (String, char) {
+    let hemisphere = if lat >= 0.0 { 'N' } else { 'S' };
+    let lat = lat.abs();
+    let degrees = lat.floor() as u32;
+    let minutes = (lat - degrees as f64) * 60.0;
+    (format!("{degrees:02}{minutes:07.4}"), hemisphere)
+}
+
+/// Formats a longitude as NMEA `dddmm.mmmm` plus its hemisphere letter.
+fn format_lon(lon: f64) -> (String, char) {
+    let hemisphere = if lon >= 0.0 { 'E' } else { 'W' };
+    let lon = lon.abs();
+    let degrees = lon.floor() as u32;
+    let minutes = (lon - degrees as f64) * 60.0;
+    (format!("{degrees:03}{minutes:07.4}"), hemisphere)
+}
+
+/// Wraps a sentence body (without leading `$` or trailing checksum) into a complete,
+/// checksummed, CRLF-terminated NMEA sentence.
+fn wrap_sentence(body: &str) -> String {
+    let checksum = nmea_checksum(body);
+    format!("${body}*{checksum:02X}\r\n")
+}
+
+impl NmeaPosition {
+    fn to_rmc(&self) -> String {
+        const MPS_TO_KNOTS: f32 = 1.943_844_5;
+        let (lat, lat_hemi) = format_lat(self.lat);
+        let (lon, lon_hemi) = format_lon(self.lon);
+        let speed_knots = self.ground_speed_mps * MPS_TO_KNOTS;
+        wrap_sentence(&format!(
+            "GPRMC,{},A,{lat},{lat_hemi},{lon},{lon_hemi},{speed_knots:.1},{:.1},{}",
+            self.timestamp.nmea_time(),
+            self.course_deg,
+            self.timestamp.nmea_date(),
+        ))
+    }
+
+    fn to_gga(&self) -> String {
+        let (lat, lat_hemi) = format_lat(self.lat);
+        let (lon, lon_hemi) = format_lon(self.lon);
+        wrap_sentence(&format!(
+            "GPGGA,{},{lat},{lat_hemi},{lon},{lon_hemi},{FIX_QUALITY_GPS},{SATELLITES_IN_VIEW:02},{HDOP:.1},{:.1},M,0.0,M,,",
+            self.timestamp.nmea_time(),
+            self.alt_hae,
+        ))
+    }
+}
+
+impl TelemMsg for NmeaPosition {
+    fn from_coords(lat: f64, lon: f64, alt_hae: f32) -> Self {
+        Self {
+            lat,
+            lon,
+            alt_hae,
+            ground_speed_mps: 0.0,
+            course_deg: 0.0,
+            timestamp: UtcTime::now(),
+        }
+    }
+
+    fn with_agent_id(self, _agent_id: &str) -> Self {
+        // NMEA 0183 GGA/RMC sentences carry no vessel/agent identity field.
+        self
+    }
+
+    fn with_velocity(
+        mut self,
+        ground_speed_mps: f32,
+        course_deg: f32,
+        _vertical_rate_mps: f32,
+    ) -> Self {
+        self.ground_speed_mps = ground_speed_mps;
+        self.course_deg = course_deg.rem_euclid(360.0);
+        self
+    }
+
+    fn with_timestamp(mut self, ts: UtcTime) -> Self {
+        self.timestamp = ts;
+        self
+    }
+
+    fn to_bytes(&self) -> Vec<u8> {
+        let mut out = self.to_rmc().into_bytes();
+        out.extend(self.to_gga().into_bytes());
+        out
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_nmea_round_trip_and_checksum() {
+        let ts = UtcTime::from_epoch_secs(1_709_642_096); // 2024-03-05 12:34:56 UTC
+        let msg = NmeaPosition::from_coords(45.6001, -122.7002, 101.0)
+            .with_timestamp(ts)
+            .with_velocity(10.0, 270.0, 0.0);
+
+        let bytes = msg.to_bytes();
+        let sentences = String::from_utf8(bytes).unwrap();
+        let mut lines = sentences.split("\r\n").filter(|l| !l.is_empty());
+        let rmc = lines.next().unwrap();
+        let gga = lines.next().unwrap();
+
+        for sentence in [rmc, gga] {
+            let body = sentence.trim_start_matches('$');
+            let (body, tail) = body.split_once('*').unwrap();
+            assert_eq!(nmea_checksum(body), u8::from_str_radix(tail, 16).unwrap());
+        }
+
+        let fields: Vec<&str> = rmc.trim_start_matches('$').split(',').collect();
+        assert_eq!(fields[0], "GPRMC");
+        assert_eq!(fields[1], "123456.00");
+        let decode_ddmm = |ddmm: f64| {
+            let degrees = (ddmm / 100.0).floor();
+            let minutes = ddmm - degrees * 100.0;
+            degrees + minutes / 60.0
+        };
+
+        let lat_ddmm: f64 = fields[3].parse().unwrap();
+        assert!((decode_ddmm(lat_ddmm) - 45.6001).abs() < 1.0 / 600_000.0);
+        let lon_ddmm: f64 = fields[5].parse().unwrap();
+        assert!((decode_ddmm(lon_ddmm) - 122.7002).abs() < 1.0 / 600_000.0);
+        assert_eq!(fields[6], "W");
+    }
+}
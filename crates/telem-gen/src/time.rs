@@ -0,0 +1,92 @@
+/// A small UTC timestamp subsystem, just enough for protocols (e.g. NMEA) that need a
+/// wall-clock time to stamp their messages with.
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A UTC calendar timestamp, with second resolution.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UtcTime {
+    pub year: i32,
+    pub month: u8,
+    pub day: u8,
+    pub hour: u8,
+    pub minute: u8,
+    pub second: u8,
+}
+
+impl UtcTime {
+    /// Reads the current system time.
+    pub fn now() -> Self {
+        let epoch_secs = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .expect("system clock before Unix epoch")
+            .as_secs();
+        Self::from_epoch_secs(epoch_secs)
+    }
+
+    /// Builds a timestamp from a Unix epoch second count, for deterministic tests and replays.
+    pub fn from_epoch_secs(epoch_secs: u64) -> Self {
+        let days = (epoch_secs / 86400) as i64;
+        let secs_of_day = (epoch_secs % 86400) as u32;
+        let (year, month, day) = civil_from_days(days);
+        Self {
+            year,
+            month,
+            day,
+            hour: (secs_of_day / 3600) as u8,
+            minute: ((secs_of_day / 60) % 60) as u8,
+            second: (secs_of_day % 60) as u8,
+        }
+    }
+
+    /// NMEA `hhmmss.ss` time field.
+    pub fn nmea_time(&self) -> String {
+        format!("{:02}{:02}{:02}.00", self.hour, self.minute, self.second)
+    }
+
+    /// NMEA `ddmmyy` date field.
+    pub fn nmea_date(&self) -> String {
+        format!(
+            "{:02}{:02}{:02}",
+            self.day,
+            self.month,
+            self.year.rem_euclid(100)
+        )
+    }
+}
+
+/// Civil calendar date (year, month, day) from a day count since the Unix epoch
+/// (1970-01-01), via Howard Hinnant's `civil_from_days` algorithm.
+fn civil_from_days(z: i64) -> (i32, u8, u8) {
+    let z = z + 719468;
+    let era = if z >= 0 { z } else { z - 146096 } / 146097;
+    let doe = (z - era * 146097) as u64; // [0, 146096]
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365; // [0, 399]
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100); // [0, 365]
+    let mp = (5 * doy + 2) / 153; // [0, 11]
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u8; // [1, 31]
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u8; // [1, 12]
+    let year = yoe as i64 + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year as i32, month, day)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_epoch_is_1970_01_01() {
+        let t = UtcTime::from_epoch_secs(0);
+        assert_eq!((t.year, t.month, t.day), (1970, 1, 1));
+        assert_eq!((t.hour, t.minute, t.second), (0, 0, 0));
+    }
+
+    #[test]
+    fn test_known_timestamp() {
+        // 2024-03-05 12:34:56 UTC
+        let t = UtcTime::from_epoch_secs(1_709_642_096);
+        assert_eq!((t.year, t.month, t.day), (2024, 3, 5));
+        assert_eq!((t.hour, t.minute, t.second), (12, 34, 56));
+        assert_eq!(t.nmea_time(), "123456.00");
+        assert_eq!(t.nmea_date(), "050324");
+    }
+}